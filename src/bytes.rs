@@ -0,0 +1,142 @@
+use core::ops::Deref;
+
+use crate::{NulError, NulTerminatedStr};
+
+/**
+A NUL-terminated byte string, without any encoding requirement.
+
+Not every C API is UTF8; many only guarantee arbitrary NUL-terminated
+bytes. `NulTerminatedBytes` carries the same single-trailing-NUL /
+no-interior-NUL invariant as `NulTerminatedStr`, but dereferences to
+`[u8]` instead of `str`.
+*/
+#[derive(Debug)]
+pub struct NulTerminatedBytes([u8]);
+
+impl NulTerminatedBytes {
+    /**
+    Creates a `NulTerminatedBytes` from a given byte slice that is
+    NUL-terminated.
+
+    If the given slice is not correctly NUL-terminated, a `NulError` is
+    returned.
+
+    # Example
+    ```
+    # use terminated::NulTerminatedBytes;
+    let ntb = NulTerminatedBytes::from_bytes_with_nul(b"foo\0");
+    assert!(ntb.is_ok());
+    ```
+    */
+    pub const fn from_bytes_with_nul(b: &[u8]) -> Result<&NulTerminatedBytes, NulError> {
+        let mut i = 0;
+        while i < b.len() {
+            if b[i] == 0 {
+                return if i == b.len() - 1 {
+                    Ok(unsafe { &*(b as *const [u8] as *const NulTerminatedBytes) })
+                } else {
+                    Err(NulError::InteriorNul(i))
+                };
+            }
+            i += 1;
+        }
+        Err(NulError::NotNulTerminated)
+    }
+
+    /**
+    Creates a `NulTerminatedBytes` from `b` without checking the
+    NUL-termination invariant.
+
+    # Safety
+
+    `b`'s last byte must be NUL, and `b` must contain no other NUL byte.
+    */
+    pub unsafe fn from_bytes_with_nul_unchecked(b: &[u8]) -> &NulTerminatedBytes {
+        &*(b as *const [u8] as *const NulTerminatedBytes)
+    }
+
+    /// Returns the content of self including the NUL terminator.
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Deref for NulTerminatedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0[..self.0.len() - 1]
+    }
+}
+
+impl<'a> From<&'a NulTerminatedStr> for &'a NulTerminatedBytes {
+    fn from(s: &'a NulTerminatedStr) -> Self {
+        // UTF8 bytes are always valid bytes.
+        unsafe { NulTerminatedBytes::from_bytes_with_nul_unchecked(s.as_str_with_nul().as_bytes()) }
+    }
+}
+
+/**
+Creates a static `NulTerminatedBytes` from a string literal.
+
+# Example
+```
+# #[macro_use] extern crate terminated;
+# fn main() {
+let b = bntstr!("Hello, World!");
+assert_eq!(b.as_bytes_with_nul(), b"Hello, World!\0");
+# }
+```
+*/
+#[macro_export]
+macro_rules! bntstr {
+    ($e:expr) => {
+        const {
+            match $crate::NulTerminatedBytes::from_bytes_with_nul(concat!($e, "\0").as_bytes()) {
+                Ok(b) => b,
+                Err(_) => panic!("bntstr!() literals must not contain an interior nul"),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NulTerminatedBytes;
+    use crate::NulError;
+
+    #[test]
+    fn test() {
+        let b = bntstr!("foo");
+        assert_eq!(&**b, b"foo");
+        assert_eq!(b.len(), 3);
+        assert_eq!(b.as_bytes_with_nul(), b"foo\0");
+    }
+
+    #[test]
+    fn test_err() {
+        assert_eq!(
+            NulTerminatedBytes::from_bytes_with_nul(b"foo").unwrap_err(),
+            NulError::NotNulTerminated
+        );
+        assert_eq!(
+            NulTerminatedBytes::from_bytes_with_nul(b"fo\0o").unwrap_err(),
+            NulError::InteriorNul(2)
+        );
+    }
+
+    #[test]
+    fn test_const() {
+        const B: &NulTerminatedBytes = bntstr!("foo");
+        assert_eq!(B.as_bytes_with_nul(), b"foo\0");
+    }
+
+    #[test]
+    fn test_from_str() {
+        use crate::NulTerminatedStr;
+
+        let nts = NulTerminatedStr::from_str_with_nul("foo\0").unwrap();
+        let ntb: &NulTerminatedBytes = nts.into();
+        assert_eq!(ntb.as_bytes_with_nul(), b"foo\0");
+    }
+}
@@ -6,7 +6,6 @@ require/guarantee UTF8 encoding. Rust has great support for dealing with UTF8,
 but C strings require a NUL terminator which Rust's `str` and `String` don't have.
 
 ```
-# #![feature(use_extern_macros)]
 # #[macro_use] extern crate terminated;
 # fn main() {
 let s = ntstr!("Hello, World!");
@@ -24,19 +23,45 @@ let ptr = s.as_ptr();
 The standard library does provide the `CStr` type that is NUL-terminated,
 but it does not use any specific encoding. It's therefore insufficient
 if your input needs to be both NUL-terminated and UTF8 encoded.
+
+`NulTerminatedStr::as_c_str` and `NulTerminatedStr::from_c_str` bridge the
+two types for free (resp. with a UTF8 check), so you can hand a
+`NulTerminatedStr` to any API that expects a `CStr`, and vice versa.
+
+Not every C API is UTF8, though; for those, [`NulTerminatedBytes`] offers
+the same NUL-termination invariant without the encoding requirement.
+
+# Owned strings
+
+This crate is `no_std` by default, but enabling the `alloc` Cargo feature
+brings in [`NulTerminatedString`], the owned, heap-allocated counterpart
+to `NulTerminatedStr`, just as `String` is to `str`. The `alloc` feature
+also brings in [`NulTerminatedWriter`], a `core::fmt::Write` sink for
+building a `NulTerminatedString` with `write!`.
 */
 
-#![cfg_attr(terminated_unstable, feature(use_extern_macros))]
 #![no_std]
 
-#[cfg(terminated_unstable)]
-#[doc(hidden)]
-pub extern crate terminated_macros;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 use core::fmt;
-use core::mem;
 use core::ops::Deref;
 
+#[cfg(feature = "alloc")]
+mod string;
+mod nulstr;
+mod bytes;
+#[cfg(feature = "alloc")]
+mod writer;
+
+#[cfg(feature = "alloc")]
+pub use crate::string::NulTerminatedString;
+pub use crate::nulstr::NulStr;
+pub use crate::bytes::NulTerminatedBytes;
+#[cfg(feature = "alloc")]
+pub use crate::writer::NulTerminatedWriter;
+
 /// An error indicating that a string is not correctly NUL-terminated.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum NulError {
@@ -64,7 +89,6 @@ A valid UTF8 string terminated by NUL, the null character.
 meaning all of `str`'s methods are available:
 
 ```
-# #![feature(use_extern_macros)]
 # #[macro_use] extern crate terminated;
 # fn main() {
 let s = ntstr!("Hello, World!");
@@ -93,18 +117,42 @@ impl NulTerminatedStr {
     assert!(nts.is_ok());
     ```
     */
-    pub fn from_str_with_nul(s: &str) -> Result<&NulTerminatedStr, NulError> {
-        let nul_pos = s.bytes().position(|b| b == 0);
-        nul_pos.ok_or(NulError::NotNulTerminated).and_then(|i| {
-            // The first (and only) nul must be at the last index
-            if i == s.len() - 1 {
-                Ok(unsafe { mem::transmute(s) })
-            } else {
-                Err(NulError::InteriorNul(i))
+    pub const fn from_str_with_nul(s: &str) -> Result<&NulTerminatedStr, NulError> {
+        NulTerminatedStr::from_str_with_nul_const(s)
+    }
+
+    /**
+    The `const fn` version of [`from_str_with_nul`](NulTerminatedStr::from_str_with_nul).
+
+    This exists as a separate, explicitly-named function because it's
+    what [`ntstr!`] calls from within a `const` block; being `const fn`
+    itself, `from_str_with_nul` can of course also be called at compile
+    time directly.
+    */
+    pub const fn from_str_with_nul_const(s: &str) -> Result<&NulTerminatedStr, NulError> {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == 0 {
+                return if i == bytes.len() - 1 {
+                    Ok(unsafe { &*(s as *const str as *const NulTerminatedStr) })
+                } else {
+                    Err(NulError::InteriorNul(i))
+                };
             }
-        })
+            i += 1;
+        }
+        Err(NulError::NotNulTerminated)
     }
 
+    /**
+    Creates a `NulTerminatedStr` from `s` without checking the
+    NUL-termination invariant.
+
+    # Safety
+
+    `s`'s last byte must be NUL, and `s` must contain no other NUL byte.
+    */
     pub unsafe fn from_str_with_nul_unchecked(s: &str) -> &NulTerminatedStr {
         &*(s as *const str as *const NulTerminatedStr)
     }
@@ -113,6 +161,47 @@ impl NulTerminatedStr {
     pub fn as_str_with_nul(&self) -> &str {
         &self.0
     }
+
+    /**
+    Borrows `self` as a `core::ffi::CStr`.
+
+    This is always valid: `self`'s bytes (including the terminator) are
+    already guaranteed to contain exactly one NUL, as the last byte.
+
+    # Example
+    ```
+    # use terminated::NulTerminatedStr;
+    let nts = NulTerminatedStr::from_str_with_nul("foo\0").unwrap();
+    assert_eq!(nts.as_c_str().to_bytes(), b"foo");
+    ```
+    */
+    pub fn as_c_str(&self) -> &core::ffi::CStr {
+        unsafe { core::ffi::CStr::from_bytes_with_nul_unchecked(self.as_str_with_nul().as_bytes()) }
+    }
+
+    /// Returns a raw pointer to `self`'s NUL-terminated bytes, suitable for
+    /// passing to C.
+    pub fn as_ptr(&self) -> *const core::ffi::c_char {
+        self.as_str_with_nul().as_ptr() as *const core::ffi::c_char
+    }
+
+    /**
+    Creates a `NulTerminatedStr` from a `core::ffi::CStr`, validating that
+    its bytes (including the implicit NUL) are UTF8.
+
+    # Example
+    ```
+    # use core::ffi::CStr;
+    # use terminated::NulTerminatedStr;
+    let c_str = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    let nts = NulTerminatedStr::from_c_str(c_str).unwrap();
+    assert_eq!(&**nts, "foo");
+    ```
+    */
+    pub fn from_c_str(s: &core::ffi::CStr) -> Result<&NulTerminatedStr, core::str::Utf8Error> {
+        core::str::from_utf8(s.to_bytes_with_nul())
+            .map(|s| unsafe { NulTerminatedStr::from_str_with_nul_unchecked(s) })
+    }
 }
 
 impl Deref for NulTerminatedStr {
@@ -145,8 +234,10 @@ impl fmt::Display for NulTerminatedStr {
 Creates a static `NulTerminatedStr` from a string literal.
 
 # Example
+A literal containing an interior NUL is rejected at compile time, not at
+runtime, since the validation happens inside a `const` block.
+
 ```
-# #![feature(use_extern_macros)]
 # #[macro_use] extern crate terminated;
 # fn main() {
 let s = ntstr!("Hello, World!");
@@ -157,29 +248,14 @@ assert_eq!(s.as_str_with_nul(), "Hello, World!\0");
 #[macro_export]
 macro_rules! ntstr { ($e:expr) => (ntstr_impl!($e)) }
 
-#[cfg(not(terminated_unstable))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! ntstr_impl {
     ($e:expr) => (
-        match $crate::NulTerminatedStr::from_str_with_nul(concat!($e, "\0")) {
-            Ok(s) => s,
-            Err(e) => panic!("{}", e),
-        }
-    )
-}
-
-#[cfg(terminated_unstable)]
-#[doc(hidden)]
-#[macro_export]
-macro_rules! ntstr_impl {
-    ($e:expr) => (
-        {
-            #[allow(unsafe_code)]
-            unsafe {
-                $crate::NulTerminatedStr::from_str_with_nul_unchecked(
-                    $crate::terminated_macros::ntstr!($e),
-                )
+        const {
+            match $crate::NulTerminatedStr::from_str_with_nul_const(concat!($e, "\0")) {
+                Ok(s) => s,
+                Err(_) => panic!("ntstr!() literals must not contain an interior nul"),
             }
         }
     )
@@ -207,4 +283,21 @@ mod tests {
         assert_eq!(NulTerminatedStr::from_str_with_nul("fo\0o\0").unwrap_err(),
             NulError::InteriorNul(2));
     }
+
+    #[test]
+    fn test_const() {
+        const NTS: Result<&NulTerminatedStr, NulError> =
+            NulTerminatedStr::from_str_with_nul_const("foo\0");
+        assert_eq!(&**NTS.unwrap(), "foo");
+    }
+
+    #[test]
+    fn test_c_str_round_trip() {
+        let nts = ntstr!("foo");
+        let c_str = nts.as_c_str();
+        assert_eq!(c_str.to_bytes(), b"foo");
+
+        let nts2 = NulTerminatedStr::from_c_str(c_str).unwrap();
+        assert_eq!(&**nts2, "foo");
+    }
 }
@@ -0,0 +1,82 @@
+use core::ffi::c_char;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use core::slice;
+use core::str;
+
+use crate::NulTerminatedStr;
+
+/**
+A thin-pointer, FFI-safe NUL-terminated UTF8 string.
+
+Unlike `NulTerminatedStr`, which is a fat `&str` under the hood and so
+cannot appear in the signature of an `extern "C"` function, `NulStr` has
+the same ABI as a single pointer (it's `#[repr(transparent)]` over a
+`NonNull<u8>`), and `Option<NulStr<'a>>` gets the null-pointer niche, so
+it lowers to exactly `*const u8`.
+*/
+#[repr(transparent)]
+pub struct NulStr<'a> {
+    ptr: NonNull<u8>,
+    _marker: PhantomData<&'a u8>,
+}
+
+impl<'a> NulStr<'a> {
+    /**
+    Creates a `NulStr` from a raw pointer.
+
+    # Safety
+
+    - `ptr` must not be null.
+    - `ptr` must point to a byte sequence that is valid UTF8, NUL-terminated,
+      and contains no interior NUL.
+    - The pointee must remain valid and must not be mutated for the
+      lifetime `'a`, which the caller supplies via `_marker`.
+    */
+    pub unsafe fn from_ptr(ptr: *const c_char, _marker: PhantomData<&'a u8>) -> NulStr<'a> {
+        NulStr {
+            ptr: NonNull::new_unchecked(ptr as *mut u8),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the underlying pointer, suitable for passing to C.
+    pub fn as_ptr(&self) -> *const c_char {
+        self.ptr.as_ptr() as *const c_char
+    }
+
+    /// Scans for the NUL terminator and returns the string slice before it.
+    pub fn to_str(&self) -> &'a str {
+        unsafe {
+            let mut len = 0;
+            while *self.ptr.as_ptr().add(len) != 0 {
+                len += 1;
+            }
+            let bytes = slice::from_raw_parts(self.ptr.as_ptr(), len);
+            str::from_utf8_unchecked(bytes)
+        }
+    }
+}
+
+impl<'a> From<&'a NulTerminatedStr> for NulStr<'a> {
+    fn from(s: &'a NulTerminatedStr) -> Self {
+        unsafe { NulStr::from_ptr(s.as_str_with_nul().as_ptr() as *const c_char, PhantomData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NulStr;
+    use crate::NulTerminatedStr;
+    use core::marker::PhantomData;
+
+    #[test]
+    fn test_round_trip() {
+        let nts = NulTerminatedStr::from_str_with_nul("foo\0").unwrap();
+        let ns: NulStr = nts.into();
+        assert_eq!(ns.to_str(), "foo");
+
+        let ns2 = unsafe { NulStr::from_ptr(ns.as_ptr(), PhantomData) };
+        assert_eq!(ns2.to_str(), "foo");
+    }
+}
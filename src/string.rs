@@ -0,0 +1,121 @@
+use alloc::string::String;
+use core::ops::Deref;
+
+use crate::{NulError, NulTerminatedStr};
+
+/**
+An owned, heap-allocated, valid UTF8 string terminated by NUL.
+
+`NulTerminatedString` is to `NulTerminatedStr` as `String` is to `str`: it
+owns its buffer and can grow, but still upholds the single-trailing-NUL /
+no-interior-NUL invariant. It dereferences to `NulTerminatedStr`, so all of
+that type's methods (and, through it, `str`'s methods) are available.
+*/
+#[derive(Debug)]
+pub struct NulTerminatedString(String);
+
+impl NulTerminatedString {
+    /**
+    Creates a `NulTerminatedString` from a given `String`.
+
+    If `s` does not already end in a NUL, one is appended. If `s` contains
+    an interior NUL (anywhere but the last byte), a `NulError` is returned.
+
+    # Example
+    ```
+    # use terminated::NulTerminatedString;
+    let nts = NulTerminatedString::from_string("Hello, World!".to_string());
+    assert!(nts.is_ok());
+    ```
+    */
+    pub fn from_string(mut s: String) -> Result<Self, NulError> {
+        match s.bytes().position(|b| b == 0) {
+            None => {
+                s.push('\0');
+                Ok(NulTerminatedString(s))
+            }
+            Some(i) if i == s.len() - 1 => Ok(NulTerminatedString(s)),
+            Some(i) => Err(NulError::InteriorNul(i)),
+        }
+    }
+
+    /// Appends the given string slice, keeping the NUL terminator last.
+    ///
+    /// Returns a `NulError` if `s` contains a NUL byte.
+    pub fn push_str(&mut self, s: &str) -> Result<(), NulError> {
+        if let Some(i) = s.bytes().position(|b| b == 0) {
+            return Err(NulError::InteriorNul(i));
+        }
+        let nul_pos = self.0.len() - 1;
+        self.0.insert_str(nul_pos, s);
+        Ok(())
+    }
+
+    /// Appends the given character, keeping the NUL terminator last.
+    ///
+    /// Returns a `NulError` if `c` is the NUL character.
+    pub fn push(&mut self, c: char) -> Result<(), NulError> {
+        if c == '\0' {
+            return Err(NulError::InteriorNul(self.len()));
+        }
+        let nul_pos = self.0.len() - 1;
+        self.0.insert(nul_pos, c);
+        Ok(())
+    }
+
+    /// Consumes `self` and returns the underlying `String` with the NUL
+    /// terminator stripped.
+    pub fn into_string(mut self) -> String {
+        self.0.pop();
+        self.0
+    }
+}
+
+impl Deref for NulTerminatedString {
+    type Target = NulTerminatedStr;
+
+    fn deref(&self) -> &NulTerminatedStr {
+        unsafe { NulTerminatedStr::from_str_with_nul_unchecked(&self.0) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NulTerminatedString;
+    use crate::NulError;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_from_string() {
+        let nts = NulTerminatedString::from_string("foo".to_string()).unwrap();
+        assert_eq!(&**nts, "foo");
+        assert_eq!(nts.as_str_with_nul(), "foo\0");
+
+        let nts = NulTerminatedString::from_string("foo\0".to_string()).unwrap();
+        assert_eq!(&**nts, "foo");
+
+        assert_eq!(
+            NulTerminatedString::from_string("fo\0o".to_string()).unwrap_err(),
+            NulError::InteriorNul(2)
+        );
+    }
+
+    #[test]
+    fn test_push() {
+        let mut nts = NulTerminatedString::from_string("foo".to_string()).unwrap();
+        nts.push_str("bar").unwrap();
+        assert_eq!(&**nts, "foobar");
+        nts.push('!').unwrap();
+        assert_eq!(&**nts, "foobar!");
+        assert_eq!(nts.as_str_with_nul(), "foobar!\0");
+
+        assert!(nts.push('\0').is_err());
+        assert!(nts.push_str("ba\0z").is_err());
+    }
+
+    #[test]
+    fn test_into_string() {
+        let nts = NulTerminatedString::from_string("foo".to_string()).unwrap();
+        assert_eq!(nts.into_string(), "foo");
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::{NulError, NulTerminatedString};
+
+/**
+A `fmt::Write` sink that builds a `NulTerminatedString` incrementally.
+
+Formatting arguments with `write!` produces plain UTF8 with no NUL
+terminator, so this buffers the written text and appends the terminator
+only once, in [`finish`](NulTerminatedWriter::finish). Any `\0` produced
+mid-stream is rejected as it's written (by returning `fmt::Error`, which
+aborts the `write!`), and its position is recorded so that `finish` can
+report it as a proper `NulError`.
+*/
+pub struct NulTerminatedWriter {
+    buf: String,
+    interior_nul: Option<usize>,
+}
+
+impl NulTerminatedWriter {
+    /// Creates an empty `NulTerminatedWriter`.
+    pub fn new() -> Self {
+        NulTerminatedWriter {
+            buf: String::new(),
+            interior_nul: None,
+        }
+    }
+
+    /**
+    Consumes the writer, appending the NUL terminator to what was written.
+
+    Returns a `NulError` if any of the written text contained a `\0`.
+    */
+    pub fn finish(self) -> Result<NulTerminatedString, NulError> {
+        if let Some(pos) = self.interior_nul {
+            return Err(NulError::InteriorNul(pos));
+        }
+        let mut buf = self.buf;
+        buf.push('\0');
+        NulTerminatedString::from_string(buf)
+    }
+}
+
+impl Default for NulTerminatedWriter {
+    fn default() -> Self {
+        NulTerminatedWriter::new()
+    }
+}
+
+impl fmt::Write for NulTerminatedWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.interior_nul.is_some() {
+            return Err(fmt::Error);
+        }
+        if let Some(i) = s.bytes().position(|b| b == 0) {
+            self.interior_nul = Some(self.buf.len() + i);
+            return Err(fmt::Error);
+        }
+        self.buf.push_str(s);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NulTerminatedWriter;
+    use core::fmt::Write;
+
+    #[test]
+    fn test_write() {
+        let mut w = NulTerminatedWriter::new();
+        let who = "World";
+        write!(w, "Hello, {who}!").unwrap();
+        let nts = w.finish().unwrap();
+        assert_eq!(&**nts, "Hello, World!");
+        assert_eq!(nts.as_str_with_nul(), "Hello, World!\0");
+    }
+
+    #[test]
+    fn test_interior_nul() {
+        let mut w = NulTerminatedWriter::new();
+        assert!(write!(w, "foo\0bar").is_err());
+        assert!(w.finish().is_err());
+    }
+}